@@ -12,10 +12,20 @@
 //! to wait blocking for you, you should try [Ws2812Direct].
 //! Bear in mind that you will have to take care of timing requirements
 //! yourself then.
+//!
+//! If you're on an async executor such as `embassy`, enable the `async`
+//! feature and use [Ws2812Async] instead, which awaits the FIFO and the
+//! latch delay rather than blocking the core.
+//!
+//! If you'd rather hand a whole frame to a DMA channel and not touch the
+//! FIFO from the CPU at all, enable the `dma` feature and use
+//! [Ws2812Direct::write_dma].
 
 use core::marker::PhantomData;
 use embedded_hal::timer::CountDown;
 use fugit::{ExtU32, HertzU32, MicrosDurationU32};
+#[cfg(feature = "dma")]
+use rp2040_hal::dma::{single_buffer, SingleChannel};
 use rp2040_hal::{
     gpio::AnyPin,
     pio::{PIOExt, StateMachineIndex, Tx, UninitStateMachine, PIO},
@@ -87,9 +97,62 @@ where
 {
     tx: Tx<(P, SM)>,
     _pin: I,
+    order: ChannelOrder,
     _color_format: PhantomData<CF>,
 }
 
+/// The PIO bit timings the assembled program is built with, and the bit
+/// frequency they're clocked at.
+///
+/// `t1`, `t2` and `t3` are the durations, in PIO cycles, of the start bit,
+/// the data bit and the stop bit respectively; together they make up one
+/// full bit period. `freq` is the target bit frequency, from which the PIO
+/// clock divider is derived as `bit_freq = freq * (t1 + t2 + t3)`.
+///
+/// Use one of the presets ([Timing::WS2812], [Timing::WS2811_SLOW]) or build
+/// a custom one for other WS2812-family parts whose datasheet specifies
+/// different T0H/T0L/T1H/T1L windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    /// Duration of the start bit, in PIO cycles.
+    pub t1: u8,
+    /// Duration of the data bit, in PIO cycles.
+    pub t2: u8,
+    /// Duration of the stop bit, in PIO cycles.
+    pub t3: u8,
+    /// Target bit frequency.
+    pub freq: HertzU32,
+}
+
+impl Timing {
+    /// Timing for 800 kHz WS2812/SK6812-family LEDs. Used by [Ws2812Direct::new]
+    /// and [Ws2812Direct::new_sk6218].
+    pub const WS2812: Timing = Timing {
+        t1: 2,
+        t2: 5,
+        t3: 3,
+        freq: HertzU32::kHz(800),
+    };
+
+    /// Timing for 400 kHz "slow mode" WS2811 LEDs.
+    pub const WS2811_SLOW: Timing = Timing {
+        t1: 2,
+        t2: 5,
+        t3: 3,
+        freq: HertzU32::kHz(400),
+    };
+
+    const fn cycles_per_bit(&self) -> u32 {
+        self.t1 as u32 + self.t2 as u32 + self.t3 as u32
+    }
+}
+
+impl Default for Timing {
+    fn default() -> Self {
+        Timing::WS2812
+    }
+}
+
 impl<P, SM, I, CF> Ws2812Direct<P, SM, I, CF>
 where
     I: AnyPin<Function = P::PinFunction>,
@@ -102,30 +165,33 @@ where
         pio: &mut PIO<P>,
         sm: UninitStateMachine<(P, SM)>,
         clock_freq: HertzU32,
+        timing: Timing,
+        order: ChannelOrder,
     ) -> Self {
         // prepare the PIO program
         let side_set = pio::SideSet::new(false, 1, false);
         let mut a = pio::Assembler::new_with_side_set(side_set);
 
-        const T1: u8 = 2; // start bit
-        const T2: u8 = 5; // data bit
-        const T3: u8 = 3; // stop bit
-        const CYCLES_PER_BIT: u32 = (T1 + T2 + T3) as u32;
-        const FREQ: HertzU32 = HertzU32::kHz(800);
+        let Timing { t1, t2, t3, freq } = timing;
+        assert!(
+            (1..=16).contains(&t1) && (1..=16).contains(&t2) && (1..=16).contains(&t3),
+            "Timing::t1, t2 and t3 must each be between 1 and 16 PIO cycles \
+             (the assembler's delay field is 4 bits wide)."
+        );
 
         let mut wrap_target = a.label();
         let mut wrap_source = a.label();
         let mut do_zero = a.label();
         a.bind(&mut wrap_target);
         // Do stop bit
-        a.out_with_delay_and_side_set(pio::OutDestination::X, 1, T3 - 1, 0);
+        a.out_with_delay_and_side_set(pio::OutDestination::X, 1, t3 - 1, 0);
         // Do start bit
-        a.jmp_with_delay_and_side_set(pio::JmpCondition::XIsZero, &mut do_zero, T1 - 1, 1);
+        a.jmp_with_delay_and_side_set(pio::JmpCondition::XIsZero, &mut do_zero, t1 - 1, 1);
         // Do data bit = 1
-        a.jmp_with_delay_and_side_set(pio::JmpCondition::Always, &mut wrap_target, T2 - 1, 1);
+        a.jmp_with_delay_and_side_set(pio::JmpCondition::Always, &mut wrap_target, t2 - 1, 1);
         a.bind(&mut do_zero);
         // Do data bit = 0
-        a.nop_with_delay_and_side_set(T2 - 1, 0);
+        a.nop_with_delay_and_side_set(t2 - 1, 0);
         a.bind(&mut wrap_source);
         let program = a.assemble_with_wrap(wrap_source, wrap_target);
 
@@ -133,7 +199,7 @@ where
         let installed = pio.install(&program).unwrap();
 
         // Configure the PIO state machine.
-        let bit_freq = FREQ * CYCLES_PER_BIT;
+        let bit_freq = freq * timing.cycles_per_bit();
         let mut int = clock_freq / bit_freq;
         let rem = clock_freq - (int * bit_freq);
         let frac = (rem * 256) / bit_freq;
@@ -172,6 +238,7 @@ where
         Self {
             tx,
             _pin: I::from(pin),
+            order,
             _color_format: PhantomData,
         }
     }
@@ -183,14 +250,51 @@ where
     P: PIOExt,
     SM: StateMachineIndex,
 {
-    /// Creates a new instance of this driver.
+    /// Creates a new instance of this driver, assuming 800 kHz WS2812 timing
+    /// and GRB channel order.
     pub fn new(
         pin: I,
         pio: &mut PIO<P>,
         sm: UninitStateMachine<(P, SM)>,
         clock_freq: HertzU32,
     ) -> Self {
-        Self::new_generic(pin, pio, sm, clock_freq)
+        Self::new_generic(
+            pin,
+            pio,
+            sm,
+            clock_freq,
+            Timing::WS2812,
+            ChannelOrder::default(),
+        )
+    }
+
+    /// Creates a new instance of this driver with custom PIO bit timings.
+    ///
+    /// Use this instead of [Ws2812Direct::new] for LEDs that don't follow
+    /// the standard 800 kHz WS2812 timing, e.g. WS2811 "slow mode" devices
+    /// (see [Timing::WS2811_SLOW]).
+    pub fn new_with_timing(
+        pin: I,
+        pio: &mut PIO<P>,
+        sm: UninitStateMachine<(P, SM)>,
+        clock_freq: HertzU32,
+        timing: Timing,
+    ) -> Self {
+        Self::new_generic(pin, pio, sm, clock_freq, timing, ChannelOrder::default())
+    }
+
+    /// Creates a new instance of this driver with a custom channel order.
+    ///
+    /// Use this instead of [Ws2812Direct::new] for strips that aren't wired
+    /// GRB, e.g. plain RGB or BGR WS2812 clones.
+    pub fn new_with_order(
+        pin: I,
+        pio: &mut PIO<P>,
+        sm: UninitStateMachine<(P, SM)>,
+        clock_freq: HertzU32,
+        order: ChannelOrder,
+    ) -> Self {
+        Self::new_generic(pin, pio, sm, clock_freq, Timing::WS2812, order)
     }
 }
 
@@ -200,14 +304,52 @@ where
     P: PIOExt,
     SM: StateMachineIndex,
 {
-    /// Creates a new instance of this driver.
+    /// Creates a new instance of this driver, assuming 800 kHz WS2812 timing
+    /// and GRBW channel order.
     pub fn new_sk6218(
         pin: I,
         pio: &mut PIO<P>,
         sm: UninitStateMachine<(P, SM)>,
         clock_freq: HertzU32,
     ) -> Self {
-        Self::new_generic(pin, pio, sm, clock_freq)
+        Self::new_generic(
+            pin,
+            pio,
+            sm,
+            clock_freq,
+            Timing::WS2812,
+            ChannelOrder::default(),
+        )
+    }
+
+    /// Creates a new instance of this driver with custom PIO bit timings.
+    ///
+    /// Use this instead of [Ws2812Direct::new_sk6218] for LEDs that don't
+    /// follow the standard 800 kHz WS2812 timing, e.g. WS2811 "slow mode"
+    /// devices (see [Timing::WS2811_SLOW]).
+    pub fn new_sk6218_with_timing(
+        pin: I,
+        pio: &mut PIO<P>,
+        sm: UninitStateMachine<(P, SM)>,
+        clock_freq: HertzU32,
+        timing: Timing,
+    ) -> Self {
+        Self::new_generic(pin, pio, sm, clock_freq, timing, ChannelOrder::default())
+    }
+
+    /// Creates a new instance of this driver with a custom channel order.
+    ///
+    /// Use this instead of [Ws2812Direct::new_sk6218] for strips that aren't
+    /// wired GRBW, e.g. RGBW WS2812 clones. The white channel is always the
+    /// last byte; only the RGB lanes are reordered.
+    pub fn new_sk6218_with_order(
+        pin: I,
+        pio: &mut PIO<P>,
+        sm: UninitStateMachine<(P, SM)>,
+        clock_freq: HertzU32,
+        order: ChannelOrder,
+    ) -> Self {
+        Self::new_generic(pin, pio, sm, clock_freq, Timing::WS2812, order)
     }
 }
 
@@ -226,6 +368,43 @@ impl ColorBytes {
     }
 }
 
+/// The wire order a strip expects its color channels in.
+///
+/// WS2812-family parts ship with several different channel orderings; the
+/// most common is GRB, but plain RGB, BGR and other permutations exist too.
+/// Pass the right one to `new_with_order` on [Ws2812Direct]/[Ws2812] instead
+/// of writing a custom [ColorFormat] impl just to swap two channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelOrder {
+    RGB,
+    RBG,
+    GRB,
+    GBR,
+    BRG,
+    BGR,
+}
+
+impl ChannelOrder {
+    const fn reorder(self, r: u8, g: u8, b: u8) -> (u8, u8, u8) {
+        match self {
+            ChannelOrder::RGB => (r, g, b),
+            ChannelOrder::RBG => (r, b, g),
+            ChannelOrder::GRB => (g, r, b),
+            ChannelOrder::GBR => (g, b, r),
+            ChannelOrder::BRG => (b, r, g),
+            ChannelOrder::BGR => (b, g, r),
+        }
+    }
+}
+
+impl Default for ChannelOrder {
+    /// Most WS2812-family strips are wired GRB, which is what this crate has
+    /// always assumed.
+    fn default() -> Self {
+        ChannelOrder::GRB
+    }
+}
+
 /// Implement this trait to support a user-defined color format.
 ///
 /// smart_leds::RGB8 and smart_leds::RGBA are implemented by the ws2812-pio
@@ -234,24 +413,24 @@ pub trait ColorFormat {
     /// Select the number of bytes per led.
     const COLOR_BYTES: ColorBytes;
 
-    /// Map the color to a 32-bit word.
-    fn to_word(self) -> u32;
+    /// Map the color to a 32-bit word, packing its channels in the given
+    /// wire order.
+    fn to_word(self, order: ChannelOrder) -> u32;
 }
 
 impl ColorFormat for smart_leds_trait::RGB8 {
     const COLOR_BYTES: ColorBytes = ColorBytes::ThreeBytes;
-    fn to_word(self) -> u32 {
-        (u32::from(self.g) << 24) | (u32::from(self.r) << 16) | (u32::from(self.b) << 8)
+    fn to_word(self, order: ChannelOrder) -> u32 {
+        let (b0, b1, b2) = order.reorder(self.r, self.g, self.b);
+        (u32::from(b0) << 24) | (u32::from(b1) << 16) | (u32::from(b2) << 8)
     }
 }
 
 impl ColorFormat for smart_leds_trait::RGBW<u8, u8> {
     const COLOR_BYTES: ColorBytes = ColorBytes::FourBytes;
-    fn to_word(self) -> u32 {
-        (u32::from(self.g) << 24)
-            | (u32::from(self.r) << 16)
-            | (u32::from(self.b) << 8)
-            | (u32::from(self.a.0))
+    fn to_word(self, order: ChannelOrder) -> u32 {
+        let (b0, b1, b2) = order.reorder(self.r, self.g, self.b);
+        (u32::from(b0) << 24) | (u32::from(b1) << 16) | (u32::from(b2) << 8) | (u32::from(self.a.0))
     }
 }
 
@@ -278,7 +457,7 @@ where
     {
         for item in iterator {
             let color: Self::Color = item.into();
-            let word = color.to_word();
+            let word = color.to_word(self.order);
 
             while !self.tx.write(word) {
                 cortex_m::asm::nop();
@@ -312,6 +491,105 @@ where
     }
 }
 
+#[cfg(feature = "dma")]
+impl<P, SM, I, CF> Ws2812Direct<P, SM, I, CF>
+where
+    I: AnyPin<Function = P::PinFunction>,
+    P: PIOExt,
+    SM: StateMachineIndex,
+{
+    /// Starts a DMA-driven transfer of a slice of already-encoded words into
+    /// the state machine's TX FIFO and returns immediately.
+    ///
+    /// `buffer` must already hold one `u32` per LED as produced by
+    /// [ColorFormat::to_word], in the same order [Ws2812Direct::write] would
+    /// have pushed them. This is the non-blocking counterpart to
+    /// [Ws2812Direct::write]: instead of spinning on the FIFO from the CPU,
+    /// `ch` feeds it in the background, so the caller is free to do other
+    /// work (or sleep) until [Ws2812DmaTransfer::is_done] reports completion.
+    ///
+    /// Call [Ws2812DmaTransfer::wait] to reclaim the channel, the buffer and
+    /// this driver once the transfer has finished.
+    pub fn write_dma<CH, B>(self, ch: CH, buffer: B) -> Ws2812DmaTransfer<CH, B, P, SM, I, CF>
+    where
+        CH: SingleChannel,
+        B: rp2040_hal::dma::ReadTarget<ReceivedWord = u32>,
+    {
+        let Ws2812Direct {
+            tx,
+            _pin,
+            order,
+            _color_format,
+        } = self;
+        let transfer = single_buffer::Config::new(ch, buffer, tx).start();
+        Ws2812DmaTransfer {
+            transfer,
+            _pin,
+            order,
+            _color_format,
+        }
+    }
+}
+
+/// A DMA transfer in progress, feeding a [Ws2812Direct]'s TX FIFO from a
+/// pre-encoded buffer of words.
+///
+/// Obtained from [Ws2812Direct::write_dma]. Poll [Ws2812DmaTransfer::is_done]
+/// from your RTIC/embassy task (or a DMA-complete interrupt) and call
+/// [Ws2812DmaTransfer::wait] once it returns `true` to get the channel, the
+/// buffer and the driver back.
+#[cfg(feature = "dma")]
+pub struct Ws2812DmaTransfer<CH, B, P, SM, I, CF = smart_leds_trait::RGB8>
+where
+    CH: SingleChannel,
+    B: rp2040_hal::dma::ReadTarget<ReceivedWord = u32>,
+    I: AnyPin<Function = P::PinFunction>,
+    P: PIOExt,
+    SM: StateMachineIndex,
+{
+    transfer: single_buffer::Transfer<CH, B, Tx<(P, SM)>>,
+    _pin: I,
+    order: ChannelOrder,
+    _color_format: PhantomData<CF>,
+}
+
+#[cfg(feature = "dma")]
+impl<CH, B, P, SM, I, CF> Ws2812DmaTransfer<CH, B, P, SM, I, CF>
+where
+    CH: SingleChannel,
+    B: rp2040_hal::dma::ReadTarget<ReceivedWord = u32>,
+    I: AnyPin<Function = P::PinFunction>,
+    P: PIOExt,
+    SM: StateMachineIndex,
+{
+    /// Returns `true` once the DMA channel has pushed the whole buffer into
+    /// the TX FIFO.
+    ///
+    /// Note that, as with [Ws2812Direct::write], the frame may still be
+    /// shifting out of the state machine for a little while after this
+    /// returns `true`; wait at least 60 microseconds before starting another
+    /// transfer.
+    pub fn is_done(&self) -> bool {
+        self.transfer.is_done()
+    }
+
+    /// Blocks until the transfer is done, then reclaims the DMA channel, the
+    /// source buffer and the [Ws2812Direct] driver.
+    pub fn wait(self) -> (CH, B, Ws2812Direct<P, SM, I, CF>) {
+        let (ch, buffer, tx) = self.transfer.wait();
+        (
+            ch,
+            buffer,
+            Ws2812Direct {
+                tx,
+                _pin: self._pin,
+                order: self.order,
+                _color_format: self._color_format,
+            },
+        )
+    }
+}
+
 /// Instance of a WS2812 LED chain.
 ///
 /// Use the [Ws2812::write] method to update the WS2812 LED chain.
@@ -387,7 +665,7 @@ where
     P: PIOExt,
     SM: StateMachineIndex,
 {
-    /// Creates a new instance of this driver.
+    /// Creates a new instance of this driver, assuming 800 kHz WS2812 timing.
     pub fn new(
         pin: I,
         pio: &mut PIO<P>,
@@ -399,6 +677,41 @@ where
 
         Self { driver, cd }
     }
+
+    /// Creates a new instance of this driver with custom PIO bit timings.
+    ///
+    /// Use this instead of [Ws2812::new] for LEDs that don't follow the
+    /// standard 800 kHz WS2812 timing, e.g. WS2811 "slow mode" devices (see
+    /// [Timing::WS2811_SLOW]).
+    pub fn new_with_timing(
+        pin: I,
+        pio: &mut PIO<P>,
+        sm: UninitStateMachine<(P, SM)>,
+        clock_freq: HertzU32,
+        cd: C,
+        timing: Timing,
+    ) -> Ws2812<P, SM, C, I, smart_leds_trait::RGB8> {
+        let driver = Ws2812Direct::new_with_timing(pin, pio, sm, clock_freq, timing);
+
+        Self { driver, cd }
+    }
+
+    /// Creates a new instance of this driver with a custom channel order.
+    ///
+    /// Use this instead of [Ws2812::new] for strips that aren't wired GRB,
+    /// e.g. plain RGB or BGR WS2812 clones.
+    pub fn new_with_order(
+        pin: I,
+        pio: &mut PIO<P>,
+        sm: UninitStateMachine<(P, SM)>,
+        clock_freq: HertzU32,
+        cd: C,
+        order: ChannelOrder,
+    ) -> Ws2812<P, SM, C, I, smart_leds_trait::RGB8> {
+        let driver = Ws2812Direct::new_with_order(pin, pio, sm, clock_freq, order);
+
+        Self { driver, cd }
+    }
 }
 
 impl<P, SM, C, I> Ws2812<P, SM, C, I, smart_leds_trait::RGBW<u8, u8>>
@@ -408,7 +721,8 @@ where
     P: PIOExt,
     SM: StateMachineIndex,
 {
-    /// Creates a new instance of this driver for SK6812 devices.
+    /// Creates a new instance of this driver for SK6812 devices, assuming
+    /// 800 kHz WS2812 timing.
     pub fn new_sk6812(
         pin: I,
         pio: &mut PIO<P>,
@@ -420,6 +734,44 @@ where
 
         Self { driver, cd }
     }
+
+    /// Creates a new instance of this driver for SK6812 devices with custom
+    /// PIO bit timings.
+    ///
+    /// Use this instead of [Ws2812::new_sk6812] for LEDs that don't follow
+    /// the standard 800 kHz WS2812 timing, e.g. WS2811 "slow mode" devices
+    /// (see [Timing::WS2811_SLOW]).
+    pub fn new_sk6812_with_timing(
+        pin: I,
+        pio: &mut PIO<P>,
+        sm: UninitStateMachine<(P, SM)>,
+        clock_freq: HertzU32,
+        cd: C,
+        timing: Timing,
+    ) -> Ws2812<P, SM, C, I, smart_leds_trait::RGBW<u8, u8>> {
+        let driver = Ws2812Direct::new_sk6218_with_timing(pin, pio, sm, clock_freq, timing);
+
+        Self { driver, cd }
+    }
+
+    /// Creates a new instance of this driver for SK6812 devices with a
+    /// custom channel order.
+    ///
+    /// Use this instead of [Ws2812::new_sk6812] for strips that aren't wired
+    /// GRBW, e.g. RGBW WS2812 clones. The white channel is always the last
+    /// byte; only the RGB lanes are reordered.
+    pub fn new_sk6812_with_order(
+        pin: I,
+        pio: &mut PIO<P>,
+        sm: UninitStateMachine<(P, SM)>,
+        clock_freq: HertzU32,
+        cd: C,
+        order: ChannelOrder,
+    ) -> Ws2812<P, SM, C, I, smart_leds_trait::RGBW<u8, u8>> {
+        let driver = Ws2812Direct::new_sk6218_with_order(pin, pio, sm, clock_freq, order);
+
+        Self { driver, cd }
+    }
 }
 
 impl<P, SM, I, C, CF> SmartLedsWrite for Ws2812<P, SM, C, I, CF>
@@ -466,3 +818,264 @@ where
         SmartLedsWrite::write(self, iterator)
     }
 }
+
+/// A wake slot for [Ws2812Async::write], shared with the interrupt handler
+/// you bind to the PIO's IRQ.
+///
+/// Construct one as a `static`, pass a `&'static` reference to it into
+/// [Ws2812Async::new]/[Ws2812Async::new_sk6812], and call
+/// [Ws2812AsyncWaker::wake] from your interrupt handler once you've observed
+/// (and cleared) the state machine's TX-FIFO-not-full condition *or* its
+/// stalled condition. This is how [Ws2812Async::write] parks the executor
+/// both while the FIFO is full and while it waits for the last word to
+/// finish shifting out, instead of busy-polling either one.
+#[cfg(feature = "async")]
+pub struct Ws2812AsyncWaker(critical_section::Mutex<core::cell::Cell<Option<core::task::Waker>>>);
+
+#[cfg(feature = "async")]
+impl Ws2812AsyncWaker {
+    /// Creates an empty wake slot.
+    pub const fn new() -> Self {
+        Self(critical_section::Mutex::new(core::cell::Cell::new(None)))
+    }
+
+    /// Wakes the task currently parked on this slot, if any.
+    ///
+    /// Call this from your PIO IRQ handler.
+    pub fn wake(&self) {
+        critical_section::with(|cs| {
+            if let Some(waker) = self.0.borrow(cs).take() {
+                waker.wake();
+            }
+        });
+    }
+
+    fn register(&self, waker: &core::task::Waker) {
+        critical_section::with(|cs| self.0.borrow(cs).set(Some(waker.clone())));
+    }
+}
+
+#[cfg(feature = "async")]
+impl Default for Ws2812AsyncWaker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Async counterpart of [Ws2812].
+///
+/// Behaves the same way, except [Ws2812Async::write] is an `async fn`: it
+/// parks on a [Ws2812AsyncWaker] instead of busy-waiting on the TX FIFO, and
+/// awaits `D`'s async delay for the 60 microsecond latch instead of blocking
+/// on a [CountDown]. Requires the `async` feature.
+///
+/// Typical usage example:
+///```ignore
+/// static WAKER: Ws2812AsyncWaker = Ws2812AsyncWaker::new();
+///
+/// #[interrupt]
+/// fn PIO0_IRQ_0() {
+///     // Clear whichever of the state machine's TX-not-full or stalled
+///     // interrupt conditions fired here, then:
+///     WAKER.wake();
+/// }
+///
+/// let mut ws = Ws2812Async::new(
+///     pins.gpio4.into_mode(),
+///     &mut pio,
+///     sm0,
+///     clocks.peripheral_clock.freq(),
+///     embassy_time::Delay,
+///     &WAKER,
+/// );
+///
+/// loop {
+///     use smart_leds::{SmartLedsWrite, RGB8};
+///     let color: RGB8 = (255, 0, 255).into();
+///
+///     ws.write([color].iter().copied()).await.unwrap();
+///
+///     // Do other stuff here...
+/// }
+///```
+#[cfg(feature = "async")]
+pub struct Ws2812Async<P, SM, I, D, CF = smart_leds_trait::RGB8>
+where
+    I: AnyPin<Function = P::PinFunction>,
+    P: PIOExt,
+    SM: StateMachineIndex,
+{
+    driver: Ws2812Direct<P, SM, I, CF>,
+    delay: D,
+    waker: &'static Ws2812AsyncWaker,
+}
+
+#[cfg(feature = "async")]
+impl<P, SM, I, D> Ws2812Async<P, SM, I, D, smart_leds_trait::RGB8>
+where
+    I: AnyPin<Function = P::PinFunction>,
+    P: PIOExt,
+    SM: StateMachineIndex,
+{
+    /// Creates a new instance of this driver.
+    pub fn new(
+        pin: I,
+        pio: &mut PIO<P>,
+        sm: UninitStateMachine<(P, SM)>,
+        clock_freq: HertzU32,
+        delay: D,
+        waker: &'static Ws2812AsyncWaker,
+    ) -> Self {
+        let driver = Ws2812Direct::new(pin, pio, sm, clock_freq);
+
+        Self {
+            driver,
+            delay,
+            waker,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<P, SM, I, D> Ws2812Async<P, SM, I, D, smart_leds_trait::RGBW<u8, u8>>
+where
+    I: AnyPin<Function = P::PinFunction>,
+    P: PIOExt,
+    SM: StateMachineIndex,
+{
+    /// Creates a new instance of this driver for SK6812 devices.
+    pub fn new_sk6812(
+        pin: I,
+        pio: &mut PIO<P>,
+        sm: UninitStateMachine<(P, SM)>,
+        clock_freq: HertzU32,
+        delay: D,
+        waker: &'static Ws2812AsyncWaker,
+    ) -> Self {
+        let driver = Ws2812Direct::new_sk6218(pin, pio, sm, clock_freq);
+
+        Self {
+            driver,
+            delay,
+            waker,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<P, SM, I, D, CF> Ws2812Async<P, SM, I, D, CF>
+where
+    I: AnyPin<Function = P::PinFunction>,
+    P: PIOExt,
+    SM: StateMachineIndex,
+    CF: ColorFormat,
+    D: embedded_hal_async::delay::DelayNs,
+{
+    /// Writes the given colors out to the LED chain, parking on this
+    /// driver's [Ws2812AsyncWaker] while the TX FIFO is full and again while
+    /// the last word finishes shifting out, then awaiting the 60 microsecond
+    /// reset/latch delay instead of blocking on any of it.
+    pub async fn write<T, J>(&mut self, iterator: T) -> Result<(), ()>
+    where
+        T: IntoIterator<Item = J>,
+        J: Into<CF>,
+    {
+        for item in iterator {
+            let word: CF = item.into();
+            let word = word.to_word(self.driver.order);
+
+            core::future::poll_fn(|cx| {
+                // Register before checking so a wake that lands between the
+                // check and going to sleep isn't missed.
+                self.waker.register(cx.waker());
+                if self.driver.tx.write(word) {
+                    core::task::Poll::Ready(())
+                } else {
+                    core::task::Poll::Pending
+                }
+            })
+            .await;
+        }
+
+        // Wait for the last word to actually finish shifting out, not just
+        // for it to have been accepted into the FIFO, before arming the
+        // latch delay (mirrors [Ws2812::write]), without blocking the
+        // executor while it does.
+        self.driver.tx.clear_stalled_flag();
+        core::future::poll_fn(|cx| {
+            self.waker.register(cx.waker());
+            if self.driver.tx.is_empty() && self.driver.tx.has_stalled() {
+                core::task::Poll::Ready(())
+            } else {
+                core::task::Poll::Pending
+            }
+        })
+        .await;
+
+        self.delay.delay_us(60).await;
+
+        Ok(())
+    }
+}
+
+/// Zero-cost [ColorFormat] adapter that applies gamma correction and an
+/// optional global brightness scale to each byte of the word its wrapped
+/// format packs, so dimming a strip looks perceptually linear instead of
+/// crushing everything below mid-brightness into near-black.
+///
+/// The correction takes effect through [ColorFormat::to_word]. The driver
+/// constructors in this crate build drivers for `smart_leds_trait::RGB8`
+/// and `smart_leds_trait::RGBW<u8, u8>` colors only, and there is no
+/// conversion from the wrapped color into `Corrected`, so no [Ws2812],
+/// [Ws2812Direct] or `Ws2812Async` with `Corrected` as its color format can
+/// be built through this crate's API.
+///
+/// `BRIGHTNESS` is a percentage-like scale out of 255 (255 = no extra
+/// scaling, only gamma correction); lower it to dim the whole strip.
+pub struct Corrected<CF, const BRIGHTNESS: u8 = 255>(CF);
+
+impl<CF, const BRIGHTNESS: u8> Corrected<CF, BRIGHTNESS> {
+    /// Wraps `inner`, e.g. `Corrected::new(RGB8::from((255, 0, 255)))`.
+    pub const fn new(inner: CF) -> Self {
+        Self(inner)
+    }
+}
+
+impl<CF, const BRIGHTNESS: u8> ColorFormat for Corrected<CF, BRIGHTNESS>
+where
+    CF: ColorFormat,
+{
+    const COLOR_BYTES: ColorBytes = CF::COLOR_BYTES;
+    fn to_word(self, order: ChannelOrder) -> u32 {
+        let word = self.0.to_word(order);
+        let bytes = word.to_be_bytes().map(|b| correct_channel(b, BRIGHTNESS));
+        u32::from_be_bytes(bytes)
+    }
+}
+
+fn correct_channel(channel: u8, brightness: u8) -> u8 {
+    let gamma_corrected = GAMMA_8[channel as usize];
+    ((u16::from(gamma_corrected) * u16::from(brightness)) / 255) as u8
+}
+
+/// 8-bit gamma-2.2 lookup table used by [Corrected] to turn a linear
+/// channel value into the PWM duty cycle that looks linear to the eye.
+#[rustfmt::skip]
+const GAMMA_8: [u8; 256] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2,
+    3, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 6, 6, 6,
+    6, 7, 7, 7, 8, 8, 8, 9, 9, 9, 10, 10, 11, 11, 11, 12,
+    12, 13, 13, 13, 14, 14, 15, 15, 16, 16, 17, 17, 18, 18, 19, 19,
+    20, 20, 21, 22, 22, 23, 23, 24, 25, 25, 26, 26, 27, 28, 28, 29,
+    30, 30, 31, 32, 33, 33, 34, 35, 35, 36, 37, 38, 39, 39, 40, 41,
+    42, 43, 43, 44, 45, 46, 47, 48, 49, 49, 50, 51, 52, 53, 54, 55,
+    56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 69, 70, 71,
+    73, 74, 75, 76, 77, 78, 79, 81, 82, 83, 84, 85, 87, 88, 89, 90,
+    91, 93, 94, 95, 97, 98, 99, 100, 102, 103, 105, 106, 107, 109, 110, 111,
+    113, 114, 116, 117, 119, 120, 121, 123, 124, 126, 127, 129, 130, 132, 133, 135,
+    137, 138, 140, 141, 143, 145, 146, 148, 149, 151, 153, 154, 156, 158, 159, 161,
+    163, 165, 166, 168, 170, 172, 173, 175, 177, 179, 181, 182, 184, 186, 188, 190,
+    192, 194, 196, 197, 199, 201, 203, 205, 207, 209, 211, 213, 215, 217, 219, 221,
+    223, 225, 227, 229, 231, 234, 236, 238, 240, 242, 244, 246, 248, 251, 253, 255,
+];